@@ -0,0 +1 @@
+pub mod arrow_function;
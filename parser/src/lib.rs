@@ -0,0 +1,5 @@
+pub mod expressions;
+pub mod to_string_settings;
+
+pub use expressions::arrow_function::*;
+pub use to_string_settings::{ESTarget, ToStringSettings};
@@ -0,0 +1,50 @@
+//! Settings controlling how an AST is printed back to source via
+//! [crate::ASTNode::to_string_from_buffer].
+
+/// Oldest ECMAScript edition the printer output is allowed to assume support for.
+/// Printing a construct newer than `target` requires lowering it to an equivalent
+/// construct first; see e.g. [ESTarget::lowers_arrow_functions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ESTarget {
+	ES5,
+	ES2015,
+	#[default]
+	ESNext,
+}
+
+impl ESTarget {
+	/// ES5 has no arrow function syntax, so targeting it (or anything older) means
+	/// every [crate::ArrowFunction] has to be lowered to a classic `function`
+	/// expression before printing.
+	pub fn lowers_arrow_functions(self) -> bool {
+		self <= ESTarget::ES5
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct ToStringSettings {
+	/// Insert whitespace/line breaks for readability rather than printing the most
+	/// compact valid form.
+	pub pretty: bool,
+	/// Selects the downlevel lowerings the printer applies; see [ESTarget]. They are
+	/// applied by the printing code itself as it prints, not by a separate pass over
+	/// the tree before printing.
+	pub target: ESTarget,
+}
+
+impl Default for ToStringSettings {
+	fn default() -> Self {
+		Self { pretty: true, target: ESTarget::default() }
+	}
+}
+
+impl ToStringSettings {
+	/// Pushes a single space onto `buf` if [Self::pretty] is set, and nothing
+	/// otherwise. Used between tokens that only need a separating gap when printing
+	/// for readability, e.g. around a lowered arrow function's `return` block.
+	pub fn add_gap<T: source_map::ToString>(&self, buf: &mut T) {
+		if self.pretty {
+			buf.push_str(" ");
+		}
+	}
+}
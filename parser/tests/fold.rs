@@ -0,0 +1,56 @@
+//! Exercises the [Fold]-based example passes, and the shorthand-expansion printing
+//! helper shipped alongside them.
+
+use parser::{ArrowFunction, Expression, Fold, ParseSettings, ToStringSettings};
+
+fn parse_arrow(source: &str) -> ArrowFunction {
+	match Expression::from_string(source.to_owned(), ParseSettings::default()) {
+		Ok(Expression::ArrowFunction(arrow)) => *arrow,
+		other => panic!("expected `{source}` to parse to an arrow function, got {other:?}"),
+	}
+}
+
+#[test]
+fn strip_async_removes_header() {
+	let mut arrow = parse_arrow("async x => x");
+	arrow.fold_with(&mut ArrowFunction::strip_async);
+	assert_eq!(arrow.to_string(&ToStringSettings::default()), "x => x");
+}
+
+#[test]
+fn normalize_body_to_block_wraps_expression_in_return() {
+	let mut arrow = parse_arrow("x => x + 1");
+	arrow.fold_with(&mut ArrowFunction::normalize_body_to_block);
+	assert_eq!(arrow.to_string(&ToStringSettings::default()), "x => { return x + 1 }");
+}
+
+#[test]
+fn normalize_body_to_block_leaves_block_bodies_alone() {
+	let mut arrow = parse_arrow("x => { return x }");
+	let before = arrow.to_string(&ToStringSettings::default());
+	arrow.fold_with(&mut ArrowFunction::normalize_body_to_block);
+	assert_eq!(arrow.to_string(&ToStringSettings::default()), before);
+}
+
+#[test]
+fn fold_with_recurses_into_a_directly_nested_arrow_function() {
+	let mut arrow = parse_arrow("async x => async y => y");
+	arrow.fold_with(&mut ArrowFunction::strip_async);
+	assert_eq!(arrow.to_string(&ToStringSettings::default()), "x => y => y");
+}
+
+#[test]
+fn expand_shorthand_parameter_parenthesises_a_bare_identifier() {
+	let arrow = parse_arrow("x => x");
+	let mut buf = String::new();
+	arrow.parameters_to_string_with_shorthand_expanded(&mut buf, &ToStringSettings::default(), 0);
+	assert_eq!(buf, "(x)");
+}
+
+#[test]
+fn expand_shorthand_parameter_leaves_an_already_parenthesised_list_alone() {
+	let arrow = parse_arrow("(a, b) => a + b");
+	let mut buf = String::new();
+	arrow.parameters_to_string_with_shorthand_expanded(&mut buf, &ToStringSettings::default(), 0);
+	assert_eq!(buf, "(a, b)");
+}
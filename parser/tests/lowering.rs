@@ -0,0 +1,71 @@
+//! Exercises the ES5 arrow-function lowering gated behind
+//! [ToStringSettings::target].
+
+use parser::{ESTarget, Expression, ParseSettings, ToStringSettings};
+
+fn es5_settings() -> ToStringSettings {
+	ToStringSettings { target: ESTarget::ES5, ..Default::default() }
+}
+
+fn parse(source: &str) -> Expression {
+	Expression::from_string(source.to_owned(), ParseSettings::default())
+		.unwrap_or_else(|err| panic!("`{source}` should parse, got {err:?}"))
+}
+
+#[test]
+fn faithful_printing_keeps_arrow_syntax() {
+	let ast = parse("x => x + 1");
+	assert_eq!(ast.to_string(&ToStringSettings::default()), "x => x + 1");
+}
+
+#[test]
+fn es5_target_lowers_to_function_expression() {
+	let ast = parse("x => x + 1");
+	assert_eq!(ast.to_string(&es5_settings()), "function (x) { return x + 1; }");
+}
+
+#[test]
+fn es5_target_lowers_multi_param_lists() {
+	let ast = parse("(a, b) => a + b");
+	assert_eq!(ast.to_string(&es5_settings()), "function (a, b) { return a + b; }");
+}
+
+#[test]
+fn es5_target_flags_but_still_validly_lowers_a_body_that_references_this() {
+	let ast = parse("() => this.value");
+	// Must not panic: this is ordinary, valid input. A `function` expression binds its
+	// own `this`, so lowering this body is semantically unsafe — but the printed
+	// output must still be valid JS (an explicit marker comment, not a broken mix of
+	// `function` header with unlowered arrow-body text).
+	let printed = ast.to_string(&es5_settings());
+	assert!(printed.contains("unsafe ES5 lowering"));
+	assert!(printed.contains("this.value"));
+	assert_eq!(printed, "function () { /* unsafe ES5 lowering: body references `this`/`arguments` */ return this.value; }");
+}
+
+#[test]
+fn es5_target_flags_a_block_bodied_arrow_that_references_this() {
+	let ast = parse("() => { return this.value; }");
+	// The previous fallback only ever checked expression bodies; a block body that
+	// rebinds `this` just as unsafely must be flagged too.
+	let printed = ast.to_string(&es5_settings());
+	assert!(printed.contains("unsafe ES5 lowering"));
+	assert!(printed.contains("this.value"));
+}
+
+#[test]
+fn es5_target_flags_this_inside_a_template_literal_interpolation() {
+	let ast = parse("() => `${this.x}`");
+	// Unlike plain string contents, a `${...}` interpolation is live code: a `this`
+	// reference inside one is exactly as unsafe to lower as one outside a template.
+	assert!(ast.to_string(&es5_settings()).contains("unsafe ES5 lowering"));
+}
+
+#[test]
+fn es5_target_does_not_flag_this_inside_a_string_or_object_key() {
+	let ast = parse(r#"() => "this""#);
+	assert!(!ast.to_string(&es5_settings()).contains("unsafe ES5 lowering"));
+
+	let ast = parse("() => ({ this: 1 })");
+	assert!(!ast.to_string(&es5_settings()).contains("unsafe ES5 lowering"));
+}
@@ -0,0 +1,56 @@
+//! Exercises partial-syntax recovery: with [ParseSettings::partial_syntax] set, a
+//! malformed arrow function body or parameter list should still produce a tree
+//! (with the failure recorded, not propagated), instead of aborting the whole parse.
+
+use parser::{Expression, ParseSettings};
+
+fn partial_settings() -> ParseSettings {
+	ParseSettings { partial_syntax: true, ..Default::default() }
+}
+
+#[test]
+fn malformed_arrow_body_recovers_under_partial_syntax() {
+	let result = Expression::from_string("x => )".to_owned(), partial_settings());
+	assert!(
+		result.is_ok(),
+		"a malformed arrow body should still produce a tree when partial_syntax is set"
+	);
+}
+
+#[test]
+fn malformed_arrow_body_still_errors_without_partial_syntax() {
+	let result = Expression::from_string("x => )".to_owned(), ParseSettings::default());
+	assert!(
+		result.is_err(),
+		"without partial_syntax, a malformed arrow body should still abort the parse"
+	);
+}
+
+#[test]
+fn malformed_parameter_list_recovers_under_partial_syntax() {
+	let result = Expression::from_string("(x, ) => x".to_owned(), partial_settings());
+	assert!(
+		result.is_ok(),
+		"a malformed parameter list should still produce a tree when partial_syntax is set"
+	);
+}
+
+#[test]
+fn body_less_arrow_at_eof_does_not_panic_under_partial_syntax() {
+	// Nothing after `=>` at all — `ExpressionOrBlock::from_reader` has no token to
+	// peek, and (as with a truncated parameter list) nothing to synchronise on or
+	// anchor a placeholder to, so the important thing is that this still returns an
+	// error instead of panicking on the unwrap of an exhausted reader.
+	assert!(Expression::from_string("x =>".to_owned(), partial_settings()).is_err());
+	assert!(Expression::from_string("() =>".to_owned(), partial_settings()).is_err());
+}
+
+#[test]
+fn parameter_list_truncated_at_eof_does_not_panic_under_partial_syntax() {
+	// Nothing to synchronise on (no `)`/`=>`) and nothing after recovery for a return
+	// type or arrow to peek at — the important thing is that this still returns an
+	// error (there's no arrow or body left to recover a tree from) instead of
+	// panicking on the ensuing unwrap of an exhausted reader.
+	let result = Expression::from_string("(a, b".to_owned(), partial_settings());
+	assert!(result.is_err());
+}
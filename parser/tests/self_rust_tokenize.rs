@@ -0,0 +1,38 @@
+//! Exercises `self-rust-tokenize` wiring: a parsed [ArrowFunction] should tokenize
+//! itself back into a `TokenStream` that reconstructs it, minting a fresh
+//! [parser::FunctionId] rather than tokenizing the literal parsed one, and emitting a
+//! synthetic [parser::Span] for any recovered-error body rather than the literal
+//! recovered range.
+#![cfg(feature = "self-rust-tokenize")]
+
+use parser::{ArrowFunction, Expression, ExpressionOrBlock, ParseSettings};
+use self_rust_tokenize::SelfRustTokenize;
+
+fn parse_arrow(source: &str) -> ArrowFunction {
+	match Expression::from_string(source.to_owned(), ParseSettings::default()) {
+		Ok(Expression::ArrowFunction(arrow)) => *arrow,
+		other => panic!("expected `{source}` to parse to an arrow function, got {other:?}"),
+	}
+}
+
+fn partial_settings() -> ParseSettings {
+	ParseSettings { partial_syntax: true, ..Default::default() }
+}
+
+#[test]
+fn arrow_function_tokenizes_to_a_fresh_function_id() {
+	let arrow = parse_arrow("x => x + 1");
+	let tokens = arrow.to_tokens().to_string();
+	assert!(tokens.contains("FunctionId :: new"), "{tokens}");
+}
+
+#[test]
+fn recovered_error_body_tokenizes_to_a_synthetic_span() {
+	let arrow = match Expression::from_string("x => )".to_owned(), partial_settings()) {
+		Ok(Expression::ArrowFunction(arrow)) => *arrow,
+		other => panic!("expected recovery to still produce an arrow function, got {other:?}"),
+	};
+	assert!(matches!(arrow.body, ExpressionOrBlock::Error(_)));
+	let tokens = arrow.body.to_tokens().to_string();
+	assert!(tokens.contains("Span :: NULL"), "{tokens}");
+}
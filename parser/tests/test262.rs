@@ -0,0 +1,98 @@
+//! Conformance harness that runs the parser against the `test262-parser-tests`
+//! corpus (<https://github.com/tc39/test262-parser-tests>).
+//!
+//! The corpus is split into three directories, checked out as a submodule under
+//! `tests/test262-parser-tests`:
+//! - `pass/`: must parse without error.
+//! - `pass-explicit/`: must parse to an AST that prints the same (so, ignoring
+//!   [Span]s) as its paired file in `pass/`, which is the same program with
+//!   all implicit syntax (e.g. ASI, shorthand) written out explicitly.
+//! - `fail/`: must fail to parse.
+//!
+//! Round-tripping (`reparse(to_string(ast))` printing the same as `ast`) is also
+//! exercised for every `pass/` fixture, which doubles as a general
+//! print-then-reparse invariant test for the whole AST. Both comparisons go through
+//! [eq_ignore_span], which compares printed output rather than walking the trees.
+
+use std::{fs, path::Path};
+
+use parser::{eq_ignore_span, ASTNode, Expression, ParseSettings, ToStringSettings};
+
+const CORPUS_ROOT: &str = "tests/test262-parser-tests";
+
+/// Reads every `.js` fixture in `tests/test262-parser-tests/<directory>`.
+///
+/// Fails loudly (rather than silently returning an empty `Vec`) both when the
+/// directory is missing and when it exists but is empty, so that forgetting to check
+/// out the `test262-parser-tests` submodule (see `.gitmodules`) shows up as a test
+/// failure instead of all three conformance tests quietly passing over zero fixtures.
+fn read_fixtures(directory: &str) -> Vec<(String, String)> {
+	let directory = Path::new(CORPUS_ROOT).join(directory);
+	let entries = fs::read_dir(&directory).unwrap_or_else(|error| {
+		panic!(
+			"could not read {}: {error}. Run `git submodule update --init` to fetch \
+			 test262-parser-tests.",
+			directory.display()
+		)
+	});
+	let mut fixtures = Vec::new();
+	for entry in entries.flatten() {
+		let path = entry.path();
+		if path.extension().and_then(|ext| ext.to_str()) == Some("js") {
+			let source = fs::read_to_string(&path).expect("fixture should be valid UTF-8");
+			let name = path.file_name().unwrap().to_string_lossy().into_owned();
+			fixtures.push((name, source));
+		}
+	}
+	assert!(!fixtures.is_empty(), "no .js fixtures found in {}", directory.display());
+	fixtures
+}
+
+fn parse(source: &str) -> Result<Expression, ()> {
+	Expression::from_string(source.to_owned(), ParseSettings::default()).map_err(|_| ())
+}
+
+/// Every `pass/` fixture must parse, and reparsing its own printed output must
+/// yield an AST that prints the same.
+#[test]
+fn pass() {
+	for (name, source) in read_fixtures("pass") {
+		let ast = parse(&source).unwrap_or_else(|_| panic!("{name} should parse but did not"));
+
+		let printed = ast.to_string(&ToStringSettings::default());
+		let reparsed =
+			parse(&printed).unwrap_or_else(|_| panic!("{name}: printed output did not reparse"));
+
+		assert!(
+			eq_ignore_span(&ast, &reparsed),
+			"{name}: print-then-reparse changed the AST shape"
+		);
+	}
+}
+
+/// Every `pass-explicit/` fixture must parse to an AST that prints the same
+/// (ignoring spans) as its `pass/` counterpart, once implicit syntax has been
+/// spelled out explicitly.
+#[test]
+fn pass_explicit() {
+	for (name, explicit_source) in read_fixtures("pass-explicit") {
+		let implicit_source = fs::read_to_string(Path::new(CORPUS_ROOT).join("pass").join(&name))
+			.unwrap_or_else(|_| panic!("{name} has no matching pass/ fixture"));
+
+		let explicit = parse(&explicit_source).unwrap_or_else(|_| panic!("{name} should parse"));
+		let implicit = parse(&implicit_source).unwrap_or_else(|_| panic!("{name} should parse"));
+
+		assert!(
+			eq_ignore_span(&explicit, &implicit),
+			"{name}: pass-explicit and pass fixtures disagree on AST shape"
+		);
+	}
+}
+
+/// Every `fail/` fixture must fail to parse.
+#[test]
+fn fail() {
+	for (name, source) in read_fixtures("fail") {
+		assert!(parse(&source).is_err(), "{name} should not parse but did");
+	}
+}